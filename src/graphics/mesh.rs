@@ -2,6 +2,88 @@ use ::*;
 use graphics::*;
 use lyon::tessellation as t;
 
+pub use lyon::tessellation::{LineCap, LineJoin};
+
+
+/// The full set of stroke knobs lyon's `StrokeOptions` exposes.
+///
+/// `DrawMode::Line(width)` only controls the line width and leaves every
+/// stroked shape with default (square) joins and butt caps; `DrawMode::Stroke`
+/// carries a `StrokeParams` instead so callers can ask for round joins, round
+/// or square caps, a custom miter limit, and a flattening tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeParams {
+    /// Thickness of the stroke.
+    pub width: f32,
+    /// Cap applied to the start of an open path.
+    pub start_cap: LineCap,
+    /// Cap applied to the end of an open path.
+    pub end_cap: LineCap,
+    /// How consecutive segments are joined.
+    pub line_join: LineJoin,
+    /// Maximum length of a miter join before it is clipped to a bevel.
+    pub miter_limit: f32,
+    /// Maximum distance between the curve and its flattened approximation.
+    pub tolerance: f32,
+}
+
+impl StrokeParams {
+    /// A stroke of the given width with lyon's default caps and joins.
+    pub fn new(width: f32) -> Self {
+        StrokeParams { width: width, ..StrokeParams::default() }
+    }
+
+    /// Set both the start and end caps to `cap`.
+    pub fn with_line_cap(mut self, cap: LineCap) -> Self {
+        self.start_cap = cap;
+        self.end_cap = cap;
+        self
+    }
+
+    /// Set the join style.
+    pub fn with_line_join(mut self, join: LineJoin) -> Self {
+        self.line_join = join;
+        self
+    }
+
+    /// Set the miter limit used by miter joins.
+    pub fn with_miter_limit(mut self, limit: f32) -> Self {
+        self.miter_limit = limit;
+        self
+    }
+
+    /// Set the flattening tolerance.
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Translate into the lyon `StrokeOptions` the tessellators consume.
+    fn to_options(&self) -> t::StrokeOptions {
+        t::StrokeOptions::default()
+            .with_line_width(self.width)
+            .with_start_cap(self.start_cap)
+            .with_end_cap(self.end_cap)
+            .with_line_join(self.line_join)
+            .with_miter_limit(self.miter_limit)
+            .with_tolerance(self.tolerance)
+    }
+}
+
+impl Default for StrokeParams {
+    fn default() -> Self {
+        let defaults = t::StrokeOptions::default();
+        StrokeParams {
+            width: defaults.line_width,
+            start_cap: defaults.start_cap,
+            end_cap: defaults.end_cap,
+            line_join: defaults.line_join,
+            miter_limit: defaults.miter_limit,
+            tolerance: defaults.tolerance,
+        }
+    }
+}
+
 
 /// A builder for creating `Mesh`es.
 ///
@@ -10,12 +92,42 @@ use lyon::tessellation as t;
 #[derive(Debug, Clone)]
 pub struct MeshBuilder {
     buffer: t::geometry_builder::VertexBuffers<Vertex>,
+    default_color: [f32; 4],
+    texture: Option<Image>,
+    uses_vertex_color: bool,
 }
 
 impl MeshBuilder {
     /// Create a new MeshBuilder.
+    ///
+    /// Vertices generated by the tessellators are stamped with a flat white
+    /// color; use `new_with_color` or `set_color` to change it.
     pub fn new() -> Self {
-        MeshBuilder { buffer: t::VertexBuffers::new() }
+        MeshBuilder {
+            buffer: t::VertexBuffers::new(),
+            default_color: [1.0, 1.0, 1.0, 1.0],
+            texture: None,
+            uses_vertex_color: false,
+        }
+    }
+
+    /// Create a new MeshBuilder whose tessellated vertices are colored with
+    /// the given color.
+    pub fn new_with_color(color: Color) -> Self {
+        MeshBuilder {
+            buffer: t::VertexBuffers::new(),
+            default_color: color.into(),
+            texture: None,
+            uses_vertex_color: true,
+        }
+    }
+
+    /// Set the color stamped onto vertices produced by subsequent builder
+    /// calls.  Geometry already accumulated keeps its original color.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.default_color = color.into();
+        self.uses_vertex_color = true;
+        self
     }
 
     /// Create a new mesh for a line of one or more connected segments.
@@ -31,20 +143,21 @@ impl MeshBuilder {
                   tolerance: f32)
                   -> &mut Self {
         {
+            let color = self.default_color;
             let buffers = &mut self.buffer;
             match mode {
                 DrawMode::Fill => {
                     // These builders have to be in separate match arms 'cause they're actually
                     // different types; one is GeometryBuilder<StrokeVertex> and the other is
                     // GeometryBuilder<FillVertex>
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     t::basic_shapes::fill_circle(t::math::point(point.x, point.y),
                                                  radius,
                                                  tolerance,
                                                  builder);
                 }
                 DrawMode::Line(line_width) => {
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let options = t::StrokeOptions::default()
                         .with_line_width(line_width)
                         .with_tolerance(tolerance);
@@ -53,6 +166,13 @@ impl MeshBuilder {
                                                    &options,
                                                    builder);
                 }
+                DrawMode::Stroke(ref params) => {
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                    t::basic_shapes::stroke_circle(t::math::point(point.x, point.y),
+                                                   radius,
+                                                   &params.to_options(),
+                                                   builder);
+                }
             };
         }
         self
@@ -68,6 +188,7 @@ impl MeshBuilder {
                    tolerance: f32)
                    -> &mut Self {
         {
+            let color = self.default_color;
             let buffers = &mut self.buffer;
             use euclid::Length;
             match mode {
@@ -75,7 +196,7 @@ impl MeshBuilder {
                     // These builders have to be in separate match arms 'cause they're actually
                     // different types; one is GeometryBuilder<StrokeVertex> and the other is
                     // GeometryBuilder<FillVertex>
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     t::basic_shapes::fill_ellipse(t::math::point(point.x, point.y),
                                                   t::math::vec2(radius1, radius2),
                                                   Length::new(0.0),
@@ -83,7 +204,7 @@ impl MeshBuilder {
                                                   builder);
                 }
                 DrawMode::Line(line_width) => {
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let options = t::StrokeOptions::default()
                         .with_line_width(line_width)
                         .with_tolerance(tolerance);
@@ -93,6 +214,14 @@ impl MeshBuilder {
                                                     &options,
                                                     builder);
                 }
+                DrawMode::Stroke(ref params) => {
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                    t::basic_shapes::stroke_ellipse(t::math::point(point.x, point.y),
+                                                    t::math::vec2(radius1, radius2),
+                                                    Length::new(0.0),
+                                                    &params.to_options(),
+                                                    builder);
+                }
             };
         }
         self
@@ -101,6 +230,7 @@ impl MeshBuilder {
     /// Create a new mesh for a series of connected lines.
     pub fn polyline(&mut self, mode: DrawMode, points: &[Point2]) -> &mut Self {
         {
+            let color = self.default_color;
             let buffers = &mut self.buffer;
             let points = points
                 .into_iter()
@@ -110,16 +240,20 @@ impl MeshBuilder {
                     // These builders have to be in separate match arms 'cause they're actually
                     // different types; one is GeometryBuilder<StrokeVertex> and the other is
                     // GeometryBuilder<FillVertex>
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let tessellator = &mut t::FillTessellator::new();
                     let options = t::FillOptions::default();
                     t::basic_shapes::fill_polyline(points, tessellator, &options, builder).unwrap();
                 }
                 DrawMode::Line(width) => {
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let options = t::StrokeOptions::default().with_line_width(width);
                     t::basic_shapes::stroke_polyline(points, false, &options, builder);
                 }
+                DrawMode::Stroke(ref params) => {
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                    t::basic_shapes::stroke_polyline(points, false, &params.to_options(), builder);
+                }
             };
         }
         self
@@ -128,6 +262,7 @@ impl MeshBuilder {
     /// Create a new mesh for a closed polygon
     pub fn polygon(&mut self, mode: DrawMode, points: &[Point2]) -> &mut Self {
         {
+            let color = self.default_color;
             let buffers = &mut self.buffer;
             let points = points
                 .into_iter()
@@ -137,16 +272,20 @@ impl MeshBuilder {
                     // These builders have to be in separate match arms 'cause they're actually
                     // different types; one is GeometryBuilder<StrokeVertex> and the other is
                     // GeometryBuilder<FillVertex>
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let tessellator = &mut t::FillTessellator::new();
                     let options = t::FillOptions::default();
                     t::basic_shapes::fill_polyline(points, tessellator, &options, builder).unwrap();
                 }
                 DrawMode::Line(width) => {
-                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder);
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
                     let options = t::StrokeOptions::default().with_line_width(width);
                     t::basic_shapes::stroke_polyline(points, true, &options, builder);
                 }
+                DrawMode::Stroke(ref params) => {
+                    let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                    t::basic_shapes::stroke_polyline(points, true, &params.to_options(), builder);
+                }
             };
         }
         self
@@ -157,6 +296,7 @@ impl MeshBuilder {
     /// Currently does not support UV's or indices.
     pub fn triangles(&mut self, triangles: &[Point2]) -> &mut Self {
         {
+            let color = self.default_color;
             assert_eq!(triangles.len() % 3, 0);
             let tris = triangles
                 .iter()
@@ -177,7 +317,7 @@ impl MeshBuilder {
                 .collect::<Vec<_>>();
             let tris = tris.chunks(3);
             let builder: &mut t::BuffersBuilder<_, _, _> =
-                &mut t::BuffersBuilder::new(&mut self.buffer, VertexBuilder);
+                &mut t::BuffersBuilder::new(&mut self.buffer, VertexBuilder { color });
             use lyon::tessellation::GeometryBuilder;
             builder.begin_geometry();
             for tri in tris {
@@ -197,6 +337,439 @@ impl MeshBuilder {
         self
     }
 
+    /// Create a new `Mesh` from a raw list of triangles, one color per vertex.
+    ///
+    /// Unlike `triangles`, which stamps the builder's current color onto every
+    /// vertex, this lets each corner carry its own color so the triangles can
+    /// be smoothly shaded (e.g. a gradient or a vertex-colored debug graph).
+    pub fn triangles_with_colors(&mut self, triangles: &[(Point2, Color)]) -> &mut Self {
+        assert_eq!(triangles.len() % 3, 0);
+        self.uses_vertex_color = true;
+        let offset = self.buffer.vertices.len() as u16;
+        for &(p, color) in triangles {
+            self.buffer
+                .vertices
+                .push(Vertex {
+                          pos: [p.x, p.y],
+                          uv: [0.0, 0.0],
+                          color: color.into(),
+                      });
+        }
+        for i in 0..(triangles.len() as u16) {
+            self.buffer.indices.push(offset + i);
+        }
+        self
+    }
+
+    /// Create a new mesh for a series of connected lines, one color per point.
+    ///
+    /// Each segment is emitted as its own `width`-thick quad (two triangles),
+    /// with each vertex tinted by its point's color so the stroke can fade
+    /// from one color to another along its length.
+    pub fn polyline_with_colors(&mut self,
+                                width: f32,
+                                points: &[(Point2, Color)])
+                                -> &mut Self {
+        if points.len() < 2 {
+            return self;
+        }
+        self.uses_vertex_color = true;
+        let half = width / 2.0;
+        for window in points.windows(2) {
+            let (p0, c0) = window[0];
+            let (p1, c1) = window[1];
+            let dx = p1.x - p0.x;
+            let dy = p1.y - p0.y;
+            let len = (dx * dx + dy * dy).sqrt();
+            if len == 0.0 {
+                continue;
+            }
+            // Normal to the segment, scaled to half the line width.
+            let nx = -dy / len * half;
+            let ny = dx / len * half;
+            let offset = self.buffer.vertices.len() as u16;
+            let c0: [f32; 4] = c0.into();
+            let c1: [f32; 4] = c1.into();
+            self.buffer.vertices.push(Vertex {
+                                          pos: [p0.x + nx, p0.y + ny],
+                                          uv: [0.0, 0.0],
+                                          color: c0,
+                                      });
+            self.buffer.vertices.push(Vertex {
+                                          pos: [p0.x - nx, p0.y - ny],
+                                          uv: [0.0, 0.0],
+                                          color: c0,
+                                      });
+            self.buffer.vertices.push(Vertex {
+                                          pos: [p1.x - nx, p1.y - ny],
+                                          uv: [0.0, 0.0],
+                                          color: c1,
+                                      });
+            self.buffer.vertices.push(Vertex {
+                                          pos: [p1.x + nx, p1.y + ny],
+                                          uv: [0.0, 0.0],
+                                          color: c1,
+                                      });
+            self.buffer
+                .indices
+                .extend_from_slice(&[offset,
+                                     offset + 1,
+                                     offset + 2,
+                                     offset,
+                                     offset + 2,
+                                     offset + 3]);
+        }
+        self
+    }
+
+    /// Begin describing a free-form path of straight and curved segments.
+    ///
+    /// The returned `PathBuilder` exposes `begin`/`line_to`/
+    /// `quadratic_bezier_to`/`cubic_bezier_to`/`close`; calling `build` on it
+    /// tessellates the assembled path into this `MeshBuilder` (filling it with
+    /// a `FillTessellator` or stroking it with a `StrokeTessellator` according
+    /// to `mode`) and hands the `MeshBuilder` back for further chaining.
+    /// Curves are flattened to within `tolerance`.
+    pub fn path(&mut self, mode: DrawMode, tolerance: f32) -> PathBuilder {
+        PathBuilder {
+            parent: self,
+            builder: lyon::path::Path::builder(),
+            mode: mode,
+            tolerance: tolerance,
+        }
+    }
+
+    /// Create a new mesh for a rectangle with rounded corners.
+    ///
+    /// `corner_radius` is clamped to half the shorter side; each corner is a
+    /// quadratic Bézier flattened to within `tolerance`.
+    pub fn rounded_rectangle(&mut self,
+                             mode: DrawMode,
+                             rect: Rect,
+                             corner_radius: f32,
+                             tolerance: f32)
+                             -> &mut Self {
+        let left = rect.x;
+        let right = rect.x + rect.w;
+        let top = rect.y;
+        let bottom = rect.y + rect.h;
+        let r = corner_radius.min(rect.w / 2.0).min(rect.h / 2.0).max(0.0);
+        {
+            let mut pb = self.path(mode, tolerance);
+            pb.begin(Point2::new(left + r, top));
+            pb.line_to(Point2::new(right - r, top));
+            pb.quadratic_bezier_to(Point2::new(right, top), Point2::new(right, top + r));
+            pb.line_to(Point2::new(right, bottom - r));
+            pb.quadratic_bezier_to(Point2::new(right, bottom), Point2::new(right - r, bottom));
+            pb.line_to(Point2::new(left + r, bottom));
+            pb.quadratic_bezier_to(Point2::new(left, bottom), Point2::new(left, bottom - r));
+            pb.line_to(Point2::new(left, top + r));
+            pb.quadratic_bezier_to(Point2::new(left, top), Point2::new(left + r, top));
+            pb.close();
+            pb.build();
+        }
+        self
+    }
+
+    /// Create a new mesh for a regular `sides`-sided polygon.
+    ///
+    /// The first vertex sits straight above `center`; the remaining vertices
+    /// are spaced evenly around a circle of the given `radius`.  Fewer than
+    /// three sides cannot describe a polygon, so such counts are ignored.
+    pub fn regular_polygon(&mut self,
+                           mode: DrawMode,
+                           center: Point2,
+                           radius: f32,
+                           sides: u32,
+                           tolerance: f32)
+                           -> &mut Self {
+        use std::f32::consts::PI;
+        if sides < 3 {
+            return self;
+        }
+        let step = 2.0 * PI / sides as f32;
+        {
+            let mut pb = self.path(mode, tolerance);
+            for i in 0..sides {
+                let angle = -PI / 2.0 + step * i as f32;
+                let point = Point2::new(center.x + radius * angle.cos(),
+                                        center.y + radius * angle.sin());
+                if i == 0 {
+                    pb.begin(point);
+                } else {
+                    pb.line_to(point);
+                }
+            }
+            pb.close();
+            pb.build();
+        }
+        self
+    }
+
+    /// Create a new mesh for a `points`-pointed star.
+    ///
+    /// Outer tips lie on a circle of `outer_radius` and the valleys between
+    /// them on a circle of `inner_radius`, both centered on `center`.  Fewer
+    /// than two points cannot describe a star, so such counts are ignored.
+    pub fn star(&mut self,
+                mode: DrawMode,
+                center: Point2,
+                inner_radius: f32,
+                outer_radius: f32,
+                points: u32,
+                tolerance: f32)
+                -> &mut Self {
+        use std::f32::consts::PI;
+        if points < 2 {
+            return self;
+        }
+        let step = PI / points as f32;
+        {
+            let mut pb = self.path(mode, tolerance);
+            for i in 0..(points * 2) {
+                let radius = if i % 2 == 0 {
+                    outer_radius
+                } else {
+                    inner_radius
+                };
+                let angle = -PI / 2.0 + step * i as f32;
+                let point = Point2::new(center.x + radius * angle.cos(),
+                                        center.y + radius * angle.sin());
+                if i == 0 {
+                    pb.begin(point);
+                } else {
+                    pb.line_to(point);
+                }
+            }
+            pb.close();
+            pb.build();
+        }
+        self
+    }
+
+    /// Add raw, caller-supplied geometry to the mesh.
+    ///
+    /// Unlike `triangles`, this keeps the vertices' UV coordinates and the
+    /// supplied index list verbatim (the indices are rebased onto whatever is
+    /// already in the buffer), and an optional `Image` is remembered as the
+    /// texture to bind when the mesh is drawn.  Passing `None` leaves the mesh
+    /// untextured, drawn against the default white image as before.
+    pub fn raw(&mut self,
+               verts: &[Vertex],
+               indices: &[u16],
+               texture: Option<Image>)
+               -> &mut Self {
+        let offset = self.buffer.vertices.len() as u16;
+        self.buffer.vertices.extend_from_slice(verts);
+        self.buffer
+            .indices
+            .extend(indices.iter().map(|i| i + offset));
+        // Assign unconditionally so that passing `None` really does leave the
+        // mesh untextured, even after an earlier call set a texture.
+        self.texture = texture;
+        // Raw vertices carry their own colors, so honor them when drawing.
+        self.uses_vertex_color = true;
+        self
+    }
+
+    /// Merge another `MeshBuilder`'s geometry into this one, transformed.
+    ///
+    /// Every vertex of `other` has the 2D portion of `transform` applied to
+    /// its position (UVs and colors are carried over unchanged) and its
+    /// indices are rebased onto the geometry already accumulated here, so a
+    /// sub-shape built once can be stamped into the same mesh at several
+    /// positions, rotations, and scales.  If this builder has no texture yet
+    /// it inherits `other`'s.
+    pub fn append(&mut self, other: &MeshBuilder, transform: Matrix4) -> &mut Self {
+        // Offsetting the indices by the current vertex count is the invariant
+        // that keeps the two index ranges from colliding after the merge.
+        let offset = self.buffer.vertices.len() as u16;
+        for vertex in &other.buffer.vertices {
+            let x = vertex.pos[0];
+            let y = vertex.pos[1];
+            let new_x = transform[(0, 0)] * x + transform[(0, 1)] * y + transform[(0, 3)];
+            let new_y = transform[(1, 0)] * x + transform[(1, 1)] * y + transform[(1, 3)];
+            self.buffer.vertices.push(Vertex {
+                                          pos: [new_x, new_y],
+                                          uv: vertex.uv,
+                                          color: vertex.color,
+                                      });
+        }
+        self.buffer
+            .indices
+            .extend(other.buffer.indices.iter().map(|i| i + offset));
+        if self.texture.is_none() {
+            self.texture = other.texture.clone();
+        }
+        self.uses_vertex_color |= other.uses_vertex_color;
+        self
+    }
+
+    /// Flatten and tessellate a finished lyon `Path` into the accumulated
+    /// vertex buffers according to `mode`.  Shared by `PathBuilder::build`
+    /// and `svg_path`.
+    fn tessellate_path(&mut self,
+                       path: &lyon::path::Path,
+                       mode: DrawMode,
+                       tolerance: f32)
+                       -> GameResult<()> {
+        use lyon::path::iterator::PathIterator;
+        let color = self.default_color;
+        let buffers = &mut self.buffer;
+        match mode {
+            DrawMode::Fill => {
+                let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                let mut tessellator = t::FillTessellator::new();
+                let options = t::FillOptions::tolerance(tolerance);
+                tessellator
+                    .tessellate_path(path.path_iter(), &options, builder)
+                    .map_err(|e| {
+                                 GameError::RenderError(format!("could not tessellate path: {:?}",
+                                                                e))
+                             })?;
+            }
+            DrawMode::Line(width) => {
+                let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                let mut tessellator = t::StrokeTessellator::new();
+                let options = t::StrokeOptions::default()
+                    .with_line_width(width)
+                    .with_tolerance(tolerance);
+                tessellator
+                    .tessellate_path(path.path_iter(), &options, builder)
+                    .map_err(|e| {
+                                 GameError::RenderError(format!("could not tessellate path: {:?}",
+                                                                e))
+                             })?;
+            }
+            DrawMode::Stroke(ref params) => {
+                let builder = &mut t::BuffersBuilder::new(buffers, VertexBuilder { color });
+                let mut tessellator = t::StrokeTessellator::new();
+                tessellator
+                    .tessellate_path(path.path_iter(), &params.to_options(), builder)
+                    .map_err(|e| {
+                                 GameError::RenderError(format!("could not tessellate path: {:?}",
+                                                                e))
+                             })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a mesh from an SVG `d` path-data string.
+    ///
+    /// The string is parsed with `svgtypes::PathParser` and each segment
+    /// (including relative variants and elliptical arcs, which are expanded to
+    /// cubic Béziers) is fed through lyon's SVG path builder, then tessellated
+    /// with `tolerance` exactly like `path`.  This makes it possible to drop a
+    /// vector icon authored in Inkscape or Illustrator straight into a `Mesh`.
+    pub fn svg_path(&mut self,
+                    path: &str,
+                    mode: DrawMode,
+                    tolerance: f32)
+                    -> GameResult<&mut Self> {
+        use svgtypes::{PathParser, PathSegment};
+        use lyon::path::builder::SvgBuilder;
+        let mut builder = lyon::path::Path::builder().with_svg();
+        for segment in PathParser::from(path) {
+            let segment = segment.map_err(|e| {
+                    GameError::ResourceLoadError(format!("invalid SVG path data: {}", e))
+                })?;
+            match segment {
+                PathSegment::MoveTo { abs, x, y } => {
+                    if abs {
+                        builder.move_to(t::math::point(x as f32, y as f32));
+                    } else {
+                        builder.relative_move_to(t::math::vector(x as f32, y as f32));
+                    }
+                }
+                PathSegment::LineTo { abs, x, y } => {
+                    if abs {
+                        builder.line_to(t::math::point(x as f32, y as f32));
+                    } else {
+                        builder.relative_line_to(t::math::vector(x as f32, y as f32));
+                    }
+                }
+                PathSegment::HorizontalLineTo { abs, x } => {
+                    if abs {
+                        builder.horizontal_line_to(x as f32);
+                    } else {
+                        builder.relative_horizontal_line_to(x as f32);
+                    }
+                }
+                PathSegment::VerticalLineTo { abs, y } => {
+                    if abs {
+                        builder.vertical_line_to(y as f32);
+                    } else {
+                        builder.relative_vertical_line_to(y as f32);
+                    }
+                }
+                PathSegment::CurveTo { abs, x1, y1, x2, y2, x, y } => {
+                    let ctrl1 = t::math::point(x1 as f32, y1 as f32);
+                    let ctrl2 = t::math::point(x2 as f32, y2 as f32);
+                    let to = t::math::point(x as f32, y as f32);
+                    if abs {
+                        builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                    } else {
+                        builder.relative_cubic_bezier_to(ctrl1.to_vector(),
+                                                         ctrl2.to_vector(),
+                                                         to.to_vector());
+                    }
+                }
+                PathSegment::SmoothCurveTo { abs, x2, y2, x, y } => {
+                    let ctrl2 = t::math::point(x2 as f32, y2 as f32);
+                    let to = t::math::point(x as f32, y as f32);
+                    if abs {
+                        builder.smooth_cubic_bezier_to(ctrl2, to);
+                    } else {
+                        builder.smooth_relative_cubic_bezier_to(ctrl2.to_vector(),
+                                                                to.to_vector());
+                    }
+                }
+                PathSegment::Quadratic { abs, x1, y1, x, y } => {
+                    let ctrl = t::math::point(x1 as f32, y1 as f32);
+                    let to = t::math::point(x as f32, y as f32);
+                    if abs {
+                        builder.quadratic_bezier_to(ctrl, to);
+                    } else {
+                        builder.relative_quadratic_bezier_to(ctrl.to_vector(), to.to_vector());
+                    }
+                }
+                PathSegment::SmoothQuadratic { abs, x, y } => {
+                    let to = t::math::point(x as f32, y as f32);
+                    if abs {
+                        builder.smooth_quadratic_bezier_to(to);
+                    } else {
+                        builder.smooth_relative_quadratic_bezier_to(to.to_vector());
+                    }
+                }
+                PathSegment::EllipticalArc { abs, rx, ry, x_axis_rotation, large_arc, sweep, x, y } => {
+                    use lyon::math::Angle;
+                    let radii = t::math::vector(rx as f32, ry as f32);
+                    let rotation = Angle::degrees(x_axis_rotation as f32);
+                    let flags = lyon::path::builder::ArcFlags {
+                        large_arc: large_arc,
+                        sweep: sweep,
+                    };
+                    let to = t::math::point(x as f32, y as f32);
+                    if abs {
+                        builder.arc_to(radii, rotation, flags, to);
+                    } else {
+                        builder.relative_arc_to(radii, rotation, flags, to.to_vector());
+                    }
+                }
+                PathSegment::ClosePath { .. } => {
+                    builder.close();
+                }
+            }
+        }
+        let path = {
+            use lyon::path::builder::FlatPathBuilder;
+            builder.build()
+        };
+        self.tessellate_path(&path, mode, tolerance)?;
+        Ok(self)
+    }
+
     /// Takes the accumulated geometry and load it into GPU memory,
     /// creating a single `Mesh`.
     pub fn build(&self, ctx: &mut Context) -> GameResult<Mesh> {
@@ -210,18 +783,91 @@ impl MeshBuilder {
                buffer: vbuf,
                slice: slice,
                blend_mode: None,
+               texture: self.texture.clone(),
+               uses_vertex_color: self.uses_vertex_color,
            })
     }
 }
 
 
-struct VertexBuilder;
+/// Describes a free-form path and tessellates it into a parent `MeshBuilder`.
+///
+/// Obtained from `MeshBuilder::path`.  The path is assembled out of `begin`
+/// (move to a new sub-path), `line_to`, `quadratic_bezier_to`,
+/// `cubic_bezier_to`, and `close` calls and realized by `build`.
+pub struct PathBuilder<'a> {
+    parent: &'a mut MeshBuilder,
+    builder: lyon::path::Builder,
+    mode: DrawMode,
+    tolerance: f32,
+}
+
+impl<'a> PathBuilder<'a> {
+    /// Start a new sub-path at the given point.
+    pub fn begin(&mut self, at: Point2) -> &mut Self {
+        use lyon::path::builder::FlatPathBuilder;
+        self.builder.move_to(t::math::point(at.x, at.y));
+        self
+    }
+
+    /// Add a straight segment from the current point to `to`.
+    pub fn line_to(&mut self, to: Point2) -> &mut Self {
+        use lyon::path::builder::FlatPathBuilder;
+        self.builder.line_to(t::math::point(to.x, to.y));
+        self
+    }
+
+    /// Add a quadratic Bézier curve with the given control point.
+    pub fn quadratic_bezier_to(&mut self, ctrl: Point2, to: Point2) -> &mut Self {
+        use lyon::path::builder::PathBuilder as LyonPathBuilder;
+        self.builder
+            .quadratic_bezier_to(t::math::point(ctrl.x, ctrl.y), t::math::point(to.x, to.y));
+        self
+    }
+
+    /// Add a cubic Bézier curve with the two given control points.
+    pub fn cubic_bezier_to(&mut self, ctrl1: Point2, ctrl2: Point2, to: Point2) -> &mut Self {
+        use lyon::path::builder::PathBuilder as LyonPathBuilder;
+        self.builder
+            .cubic_bezier_to(t::math::point(ctrl1.x, ctrl1.y),
+                             t::math::point(ctrl2.x, ctrl2.y),
+                             t::math::point(to.x, to.y));
+        self
+    }
+
+    /// Close the current sub-path back to its starting point.
+    pub fn close(&mut self) -> &mut Self {
+        use lyon::path::builder::FlatPathBuilder;
+        self.builder.close();
+        self
+    }
+
+    /// Flatten and tessellate the assembled path into the parent
+    /// `MeshBuilder`, returning it so building can continue.
+    pub fn build(self) -> &'a mut MeshBuilder {
+        let path = {
+            use lyon::path::builder::FlatPathBuilder;
+            self.builder.build()
+        };
+        self.parent
+            .tessellate_path(&path, self.mode, self.tolerance)
+            .expect("could not tessellate path");
+        self.parent
+    }
+}
+
+
+/// Stamps the builder's current color onto every vertex the tessellators emit.
+struct VertexBuilder {
+    color: [f32; 4],
+}
 
 impl t::VertexConstructor<t::FillVertex, Vertex> for VertexBuilder {
     fn new_vertex(&mut self, vertex: t::FillVertex) -> Vertex {
         Vertex {
             pos: [vertex.position.x, vertex.position.y],
             uv: [0.0, 0.0],
+            color: self.color,
         }
     }
 }
@@ -231,6 +877,7 @@ impl t::VertexConstructor<t::StrokeVertex, Vertex> for VertexBuilder {
         Vertex {
             pos: [vertex.position.x, vertex.position.y],
             uv: [0.0, 0.0],
+            color: self.color,
         }
     }
 }
@@ -239,11 +886,13 @@ impl t::VertexConstructor<t::StrokeVertex, Vertex> for VertexBuilder {
 /// 2D polygon mesh.
 ///
 /// All of its methods are just shortcuts for doing the same operations via a `MeshBuilder`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Mesh {
     buffer: gfx::handle::Buffer<gfx_device_gl::Resources, Vertex>,
     slice: gfx::Slice<gfx_device_gl::Resources>,
     blend_mode: Option<BlendMode>,
+    texture: Option<Image>,
+    uses_vertex_color: bool,
 }
 
 
@@ -295,12 +944,62 @@ impl Mesh {
         mb.build(ctx)
     }
 
+    /// Create a new mesh for a rectangle with rounded corners.
+    pub fn new_rounded_rectangle(ctx: &mut Context,
+                                 mode: DrawMode,
+                                 rect: Rect,
+                                 corner_radius: f32,
+                                 tolerance: f32)
+                                 -> GameResult<Mesh> {
+        let mut mb = MeshBuilder::new();
+        mb.rounded_rectangle(mode, rect, corner_radius, tolerance);
+        mb.build(ctx)
+    }
+
+    /// Create a new mesh for a regular `sides`-sided polygon.
+    pub fn new_regular_polygon(ctx: &mut Context,
+                               mode: DrawMode,
+                               center: Point2,
+                               radius: f32,
+                               sides: u32,
+                               tolerance: f32)
+                               -> GameResult<Mesh> {
+        let mut mb = MeshBuilder::new();
+        mb.regular_polygon(mode, center, radius, sides, tolerance);
+        mb.build(ctx)
+    }
+
+    /// Create a new mesh for a `points`-pointed star.
+    pub fn new_star(ctx: &mut Context,
+                    mode: DrawMode,
+                    center: Point2,
+                    inner_radius: f32,
+                    outer_radius: f32,
+                    points: u32,
+                    tolerance: f32)
+                    -> GameResult<Mesh> {
+        let mut mb = MeshBuilder::new();
+        mb.star(mode, center, inner_radius, outer_radius, points, tolerance);
+        mb.build(ctx)
+    }
+
     /// Create a new `Mesh` from a raw list of triangles.
     pub fn from_triangles(ctx: &mut Context, triangles: &[Point2]) -> GameResult<Mesh> {
         let mut mb = MeshBuilder::new();
         mb.triangles(triangles);
         mb.build(ctx)
     }
+
+    /// Create a new `Mesh` from raw vertices, indices, and an optional texture.
+    pub fn from_raw(ctx: &mut Context,
+                    verts: &[Vertex],
+                    indices: &[u16],
+                    texture: Option<Image>)
+                    -> GameResult<Mesh> {
+        let mut mb = MeshBuilder::new();
+        mb.raw(verts, indices, texture);
+        mb.build(ctx)
+    }
 }
 
 impl Drawable for Mesh {
@@ -308,11 +1007,26 @@ impl Drawable for Mesh {
         let gfx = &mut ctx.gfx_context;
         gfx.update_instance_properties(param)?;
 
+        // Select between the flat `DrawParam` color and the per-vertex colors
+        // baked into the buffer, then re-upload the globals so the shader's
+        // `u_UseVertColor` mix picks the right one.
+        gfx.globals.use_vertex_color = if self.uses_vertex_color { 1.0 } else { 0.0 };
+        gfx.encoder
+            .update_buffer(&gfx.data.globals, &[gfx.globals], 0)?;
+
         gfx.data.vbuf = self.buffer.clone();
-        gfx.data.tex.0 = gfx.white_image.texture.clone();
+        gfx.data.tex.0 = match self.texture {
+            Some(ref image) => image.texture.clone(),
+            None => gfx.white_image.texture.clone(),
+        };
 
         gfx.draw(Some(&self.slice))?;
 
+        // Reset the flag so it does not leak into subsequent draws.
+        gfx.globals.use_vertex_color = 0.0;
+        gfx.encoder
+            .update_buffer(&gfx.data.globals, &[gfx.globals], 0)?;
+
         Ok(())
     }
     fn set_blend_mode(&mut self, mode: Option<BlendMode>) {
@@ -0,0 +1,75 @@
+//! The `graphics` module performs the actual drawing of images, text, and other
+//! objects with the `Drawable` trait.  It also handles the creation and
+//! management of the low-level `gfx` rendering pipeline.
+
+use gfx;
+
+mod mesh;
+
+pub use self::mesh::*;
+
+/// A RGBA color.
+///
+/// Converts losslessly into the `[f32; 4]` the shaders consume.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Color {
+    /// Red component
+    pub r: f32,
+    /// Green component
+    pub g: f32,
+    /// Blue component
+    pub b: f32,
+    /// Alpha component
+    pub a: f32,
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+gfx_defines!{
+    /// Structure containing fundamental vertex data.
+    vertex Vertex {
+        pos: [f32; 2] = "a_Pos",
+        uv: [f32; 2] = "a_Uv",
+        color: [f32; 4] = "a_VertColor",
+    }
+
+    /// Values that are different for every draw call.
+    constant Globals {
+        transform: [[f32; 4]; 4] = "u_Transform",
+        color: [f32; 4] = "u_Color",
+        // When zero the vertex shader uses the per-instance `u_Color`
+        // exactly as before; when non-zero it instead takes the color from the
+        // vertex attribute, so flat-colored draws keep their old behavior.
+        use_vertex_color: f32 = "u_UseVertColor",
+    }
+
+    /// The rendering pipeline used to draw everything.
+    pipeline pipe {
+        vbuf: gfx::VertexBuffer<Vertex> = (),
+        tex: gfx::TextureSampler<[f32; 4]> = "t_Texture",
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        out: gfx::BlendTarget<gfx::format::Srgba8> =
+            ("Target0", gfx::state::ColorMask::all(), gfx::preset::blend::ALPHA),
+    }
+}
+
+/// Source GLSL for the default shader pair.
+pub const VERTEX_SHADER_SOURCE: &[u8] = include_bytes!("../../resources/shader/basic_150.glslv");
+/// Source GLSL for the default shader pair.
+pub const FRAGMENT_SHADER_SOURCE: &[u8] = include_bytes!("../../resources/shader/basic_150.glslf");
+
+/// Specifies whether a mesh should be drawn filled or as an outline.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DrawMode {
+    /// A filled shape.
+    Fill,
+    /// An outline with the given line width.
+    Line(f32),
+    /// An outline with a full set of stroke options (caps, joins, miter
+    /// limit, tolerance) instead of just a width.
+    Stroke(StrokeParams),
+}